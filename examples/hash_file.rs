@@ -0,0 +1,38 @@
+//! Streams a file through `MultilinearHasher`'s `digest::Digest`
+//! implementation in fixed-size chunks and prints the hex digest. This
+//! demonstrates incremental hashing of data too large to read into memory
+//! at once.
+//!
+//! Requires the `digest` feature:
+//!     cargo run --example hash_file --features digest -- <path>
+
+use std::env;
+use std::fs::File;
+use std::io::{self, Read};
+
+use digest::Digest;
+use rustc_hash::MultilinearHasher;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+fn main() -> io::Result<()> {
+    let path = env::args().nth(1).expect("usage: hash_file <path>");
+    let mut file = File::open(path)?;
+    let mut hasher = MultilinearHasher::default();
+    let mut buf = [0u8; CHUNK_SIZE];
+
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        Digest::update(&mut hasher, &buf[..n]);
+    }
+
+    for byte in hasher.finalize() {
+        print!("{:02x}", byte);
+    }
+    println!();
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! Shared hardware-AES bulk-hashing backend, used by both
+//! [`crate::AesHasher`] and [`crate::FxHasher`]'s accelerated bulk path for
+//! large slices (see `mum_add_hasher.rs`). Only compiled when the `aes`
+//! crate feature is enabled.
+//!
+//! Runtime-detected: on a CPU without AES-NI (x86-64) or the crypto
+//! extensions (aarch64), [`hash_bytes`] returns `None` and the caller is
+//! expected to fall back to the portable scalar `multiply_mix` loop.
+//!
+//! On x86-64, detection goes through `std::is_x86_feature_detected!`, which
+//! has no `core`-only equivalent (unlike aarch64's
+//! `core::arch::is_aarch64_feature_detected!`), so the `aes` feature also
+//! requires `std` there; without it this backend is unreachable and
+//! `hash_bytes` always returns `None` on x86-64.
+
+#[cfg(all(target_arch = "x86_64", feature = "std"))]
+use std::is_x86_feature_detected;
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use core::arch::x86_64::*;
+
+    /// # Safety
+    /// Caller must have checked that the `aes` and `sse2` target features
+    /// are available, e.g. via `is_x86_feature_detected!`.
+    #[target_feature(enable = "aes,sse2")]
+    pub unsafe fn hash_bytes(bytes: &[u8], seed1: u64, seed2: u64, key1: u64, key2: u64) -> u64 {
+        let key = _mm_set_epi64x(key2 as i64, key1 as i64);
+        let mut state = _mm_set_epi64x(seed2 as i64, seed1 as i64);
+        let len = bytes.len();
+
+        let mut chunks = bytes.chunks_exact(16);
+        for block in &mut chunks {
+            let b = _mm_loadu_si128(block.as_ptr() as *const __m128i);
+            state = _mm_aesenc_si128(_mm_xor_si128(state, b), key);
+        }
+
+        // Absorb the tail the same way the scalar path does its short
+        // reads: an overlapping load of the last 16 bytes.
+        let rem = chunks.remainder();
+        if !rem.is_empty() || len == 0 {
+            let mut tail = [0u8; 16];
+            if len >= 16 {
+                tail.copy_from_slice(&bytes[len - 16..]);
+            } else {
+                tail[16 - rem.len()..].copy_from_slice(rem);
+            }
+            let b = _mm_loadu_si128(tail.as_ptr() as *const __m128i);
+            state = _mm_aesenc_si128(_mm_xor_si128(state, b), key);
+        }
+
+        // Finalize with two more rounds that mix in the length, so e.g.
+        // `[0, 0]` and `[0, 0, 0, 0]` don't collapse to the same state.
+        let len_block = _mm_set_epi64x(len as i64, len as i64);
+        state = _mm_aesenc_si128(_mm_xor_si128(state, len_block), key);
+        state = _mm_aesenc_si128(state, key);
+        _mm_cvtsi128_si64(state) as u64
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod aarch64 {
+    use core::arch::aarch64::*;
+
+    /// # Safety
+    /// Caller must have checked that the `aes` target feature is available,
+    /// e.g. via `core::arch::is_aarch64_feature_detected!("aes")`.
+    #[target_feature(enable = "aes")]
+    pub unsafe fn hash_bytes(bytes: &[u8], seed1: u64, seed2: u64, key1: u64, key2: u64) -> u64 {
+        let key = vreinterpretq_u8_u64(vcombine_u64(vcreate_u64(key1), vcreate_u64(key2)));
+        let mut state =
+            vreinterpretq_u8_u64(vcombine_u64(vcreate_u64(seed1), vcreate_u64(seed2)));
+        let len = bytes.len();
+
+        let round = |state: uint8x16_t, block: uint8x16_t| -> uint8x16_t {
+            // `vaeseq_u8` XORs in the key and does SubBytes+ShiftRows; we
+            // pass it a zero key and fold `key` in ourselves to mirror
+            // `_mm_aesenc_si128`'s xor-then-encrypt semantics, then run
+            // MixColumns with `vaesmcq_u8`.
+            let mixed = vaesmcq_u8(vaeseq_u8(veorq_u8(state, block), vdupq_n_u8(0)));
+            veorq_u8(mixed, key)
+        };
+
+        let mut chunks = bytes.chunks_exact(16);
+        for block in &mut chunks {
+            let b = vld1q_u8(block.as_ptr());
+            state = round(state, b);
+        }
+
+        let rem = chunks.remainder();
+        if !rem.is_empty() || len == 0 {
+            let mut tail = [0u8; 16];
+            if len >= 16 {
+                tail.copy_from_slice(&bytes[len - 16..]);
+            } else {
+                tail[16 - rem.len()..].copy_from_slice(rem);
+            }
+            let b = vld1q_u8(tail.as_ptr());
+            state = round(state, b);
+        }
+
+        let len_block = vreinterpretq_u8_u64(vdupq_n_u64(len as u64));
+        state = round(state, len_block);
+        state = round(state, vdupq_n_u8(0));
+        vgetq_lane_u64(vreinterpretq_u64_u8(state), 0)
+    }
+}
+
+/// Hashes `bytes` with hardware AES round instructions keyed off
+/// `seed1`/`seed2`/`key1`/`key2`, or returns `None` if no supported AES
+/// instructions were detected on this CPU at runtime.
+#[inline]
+pub fn hash_bytes(bytes: &[u8], seed1: u64, seed2: u64, key1: u64, key2: u64) -> Option<u64> {
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    {
+        if is_x86_feature_detected!("aes") && is_x86_feature_detected!("sse2") {
+            // SAFETY: both required target features were just checked.
+            return Some(unsafe { x86::hash_bytes(bytes, seed1, seed2, key1, key2) });
+        }
+    }
+    #[cfg(target_arch = "aarch64")]
+    {
+        if core::arch::is_aarch64_feature_detected!("aes") {
+            // SAFETY: the required target feature was just checked.
+            return Some(unsafe { aarch64::hash_bytes(bytes, seed1, seed2, key1, key2) });
+        }
+    }
+    #[allow(unreachable_code)]
+    None
+}
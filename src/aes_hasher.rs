@@ -0,0 +1,246 @@
+use core::hash::Hasher;
+use core::convert::TryInto;
+
+// The first eight words are the fractional hexadecimal digits of pi that
+// also fill the second half of `mum_add_hasher.rs`'s `ENTROPY` table
+// (0x9216d5d98979fb1b...); the last eight are further fixed constants.
+const ENTROPY: [u64; 16] = [
+    0x9216d5d98979fb1b, 0xd1310ba698dfb5ac, 0x2ffd72dbd01adfb7, 0xb8e1afed6a267e96,
+    0xba7c9045f12c7f99, 0x24a19947b3916cf7, 0x0801f2e2858efc16, 0x636920d871574e69,
+    0x5b4321b4a1b0c4b4, 0x5a1158c4c58c9815, 0x0ef956141fe24e99, 0x08e4f89111e90c7e,
+    0x24edff4b1cfae00c, 0xbb5f5a5c3fbb3ae2, 0x876920cc2cf9cb59, 0x670c9200107d4642,
+];
+
+const SEED1: u64 = ENTROPY[0];
+const SEED2: u64 = ENTROPY[1];
+const AES_KEY1: u64 = ENTROPY[2];
+const AES_KEY2: u64 = ENTROPY[3];
+const PREVENT_TRIVIAL_ZERO_COLLAPSE: u64 = 0x718bcd5882154aee;
+
+#[inline]
+fn multiply_mix(x: u64, y: u64) -> u64 {
+    // See `mum_add_hasher.rs` for a full explanation of this mixing step.
+    let full = (x as u128) * (y as u128);
+    let lo = full as u64;
+    let hi = (full >> 64) as u64;
+    lo ^ hi
+}
+
+/// The same short-input logic used by the other hashers, kept here so this
+/// module can be used standalone whether or not hardware AES is available.
+#[inline]
+fn hash_bytes_scalar(bytes: &[u8]) -> u64 {
+    let len = bytes.len();
+    let mut s0 = SEED1;
+    let mut s1 = SEED2;
+    if len <= 16 {
+        if len >= 8 {
+            s0 ^= u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+            s1 ^= u64::from_le_bytes(bytes[len - 8..].try_into().unwrap());
+        } else if len >= 4 {
+            s0 ^= u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as u64;
+            s1 ^= u32::from_le_bytes(bytes[len - 4..].try_into().unwrap()) as u64;
+        } else if len > 0 {
+            let lo = bytes[0];
+            let mid = bytes[len / 2];
+            let hi = bytes[len - 1];
+            s0 ^= lo as u64;
+            s1 ^= ((hi as u64) << 8) | mid as u64;
+        }
+    } else {
+        let mut off = 0;
+        while off < len - 16 {
+            let x = u64::from_le_bytes(bytes[off..off + 8].try_into().unwrap());
+            let y = u64::from_le_bytes(bytes[off + 8..off + 16].try_into().unwrap());
+            let t = multiply_mix(s0 ^ x, PREVENT_TRIVIAL_ZERO_COLLAPSE ^ y);
+            s0 = s1;
+            s1 = t;
+            off += 16;
+        }
+        let suffix = &bytes[len - 16..];
+        s0 ^= u64::from_le_bytes(suffix[0..8].try_into().unwrap());
+        s1 ^= u64::from_le_bytes(suffix[8..16].try_into().unwrap());
+    }
+
+    multiply_mix(s0, s1) ^ len as u64
+}
+
+/// Hashes `bytes` using hardware AES round instructions when available at
+/// runtime, falling back to the portable scalar mix otherwise. The
+/// arch-specific AES backend lives in `aes_bulk.rs`, shared with
+/// `FxHasher`'s own accelerated bulk path for large slices.
+///
+/// Like `MumAddHasher::hash_bytes`, inputs at or below the 16-byte
+/// threshold always take the scalar path, since AES setup would dominate
+/// at that size.
+#[inline]
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    if bytes.len() <= 16 {
+        return hash_bytes_scalar(bytes);
+    }
+    match crate::aes_bulk::hash_bytes(bytes, SEED1, SEED2, AES_KEY1, AES_KEY2) {
+        Some(h) => h,
+        None => hash_bytes_scalar(bytes),
+    }
+}
+
+/// Fast non-collision-resistant hash that uses hardware AES round
+/// instructions to mix byte slices when they're available (x86-64 `aes`,
+/// aarch64 crypto extensions), and otherwise falls back to the same
+/// `multiply_mix` scalar path as [`crate::FxHasher`].
+///
+/// Requires the `aes` crate feature. Detection happens at runtime, not at
+/// compile time, so a single binary works correctly (just slower) on CPUs
+/// without AES-NI.
+#[derive(Default, Clone)]
+pub struct AesHasher {
+    hash: u64,
+    rng: u64,
+    entropy_idx: usize,
+}
+
+impl AesHasher {
+    #[inline]
+    pub fn with_seed(seed: usize) -> Self {
+        Self { hash: seed as u64, rng: seed as u64, entropy_idx: 0 }
+    }
+
+    #[inline]
+    fn gen_rng(&mut self) -> u64 {
+        // See `MumAddHasher::gen_rng` for why this simple additive walk is
+        // good enough here.
+        self.entropy_idx %= 16;
+        self.rng = self.rng.wrapping_add(ENTROPY[self.entropy_idx]);
+        self.entropy_idx += 1;
+        self.rng
+    }
+
+    #[inline]
+    fn add_to_hash(&mut self, x: u64) {
+        let h = multiply_mix(x, self.gen_rng());
+        self.hash = self.hash.wrapping_add(h);
+    }
+
+    #[inline]
+    fn double_add_to_hash(&mut self, x: u64, y: u64) {
+        let h = multiply_mix(x ^ self.gen_rng(), y ^ self.gen_rng());
+        self.hash = self.hash.wrapping_add(h);
+    }
+}
+
+impl Hasher for AesHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.add_to_hash(hash_bytes(bytes))
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.double_add_to_hash(i as u64, (i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, _len: usize) {
+        // Most cases will specialize hash_slice anyway which calls write,
+        // which encodes the length already.
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) {
+        // We don't need anything special here.
+        self.write(s.as_bytes())
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_inputs_always_take_the_scalar_path() {
+        // `hash_bytes` should short-circuit to `hash_bytes_scalar` for every
+        // length up to and including the 16-byte threshold, regardless of
+        // whether AES is available on this CPU.
+        let buf: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        for len in [0usize, 1, 3, 4, 7, 8, 15, 16] {
+            let input = &buf[..len];
+            assert_eq!(hash_bytes(input), hash_bytes_scalar(input), "len={len}");
+        }
+    }
+
+    #[test]
+    fn sample_short_inputs_match_scalar_path() {
+        assert_eq!(hash_bytes(&[]), hash_bytes_scalar(&[]));
+        assert_eq!(hash_bytes(b"uwu"), hash_bytes_scalar(b"uwu"));
+        assert_eq!(hash_bytes(b"sixteen bytes!!!"), hash_bytes_scalar(b"sixteen bytes!!!"));
+    }
+
+    #[test]
+    fn long_input_is_deterministic() {
+        let mut input = [0u8; 64];
+        let mut i = 0;
+        while i < input.len() {
+            input[i] = i as u8;
+            i += 1;
+        }
+        assert_eq!(hash_bytes(&input), hash_bytes(&input));
+    }
+
+    #[test]
+    fn crossing_the_16_byte_boundary_changes_the_hash() {
+        let a: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let mut b = [0u8; 17];
+        b[..16].copy_from_slice(&a);
+        b[16] = 99;
+        assert_ne!(hash_bytes(&a), hash_bytes(&b));
+    }
+
+    #[test]
+    fn hasher_write_is_deterministic_across_instances() {
+        let mut a = AesHasher::with_seed(42);
+        let mut b = AesHasher::with_seed(42);
+        let long_input = b"hello, world! this is longer than 16 bytes";
+        a.write(long_input);
+        b.write(long_input);
+        assert_eq!(a.finish(), b.finish());
+    }
+
+    #[test]
+    fn different_seeds_give_different_output() {
+        let mut a = AesHasher::with_seed(1);
+        let mut b = AesHasher::with_seed(2);
+        a.write_u64(123);
+        b.write_u64(123);
+        assert_ne!(a.finish(), b.finish());
+    }
+}
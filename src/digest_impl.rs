@@ -0,0 +1,52 @@
+//! Optional [`digest`] crate integration, letting [`MultilinearHasher`] and
+//! [`PolyHasher`] be used anywhere a RustCrypto [`Digest`](digest::Digest) is
+//! expected (checksums, HMAC-style constructions, content addressing).
+//!
+//! Requires the `digest` crate feature. The "digest" produced is just the
+//! 8-byte little-endian [`Hasher::finish`] value reinterpreted as bytes;
+//! this is not a cryptographic hash and must not be used anywhere collision
+//! resistance against an adversary is required.
+
+use core::hash::Hasher;
+
+use digest::consts::U8;
+use digest::generic_array::GenericArray;
+use digest::{FixedOutput, HashMarker, OutputSizeUser, Reset, Update};
+
+use crate::{MultilinearHasher, PolyHasher};
+
+macro_rules! impl_digest {
+    ($ty:ty) => {
+        impl Update for $ty {
+            #[inline]
+            fn update(&mut self, data: &[u8]) {
+                Hasher::write(self, data);
+            }
+        }
+
+        impl OutputSizeUser for $ty {
+            type OutputSize = U8;
+        }
+
+        impl FixedOutput for $ty {
+            #[inline]
+            fn finalize_into(self, out: &mut GenericArray<u8, U8>) {
+                out.copy_from_slice(&Hasher::finish(&self).to_le_bytes());
+            }
+        }
+
+        impl Reset for $ty {
+            #[inline]
+            fn reset(&mut self) {
+                *self = <$ty>::default();
+            }
+        }
+
+        // Required by `digest::Digest`'s blanket impl to mark that this type
+        // is a hash function and not, say, a MAC or generic XOF.
+        impl HashMarker for $ty {}
+    };
+}
+
+impl_digest!(MultilinearHasher);
+impl_digest!(PolyHasher);
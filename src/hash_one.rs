@@ -0,0 +1,93 @@
+//! Type-specialized hashing for the Fx family of hashers.
+//!
+//! Hashing a key through the generic [`Hash`](core::hash::Hash) trait means
+//! going through at least one extra dispatch (`Hash::hash`) and, for
+//! compound types, a `write_length_prefix` call per field even though our
+//! hashers make that a no-op. For the key shapes that dominate real
+//! `HashMap`/`HashSet` usage -- integers, `&str`/`String`, `&[u8]`, `char`
+//! -- we can skip straight to feeding an already-seeded hasher its bytes or
+//! bit pattern once and finalizing, which is what [`FxHashOne`] exists for.
+
+use core::hash::Hasher;
+
+/// Hashes a value of type `T` directly into an already-seeded hasher,
+/// specialized per key shape to skip the generic `Hash::hash` dispatch.
+///
+/// See the module docs for the motivation. Implemented for the integer
+/// types, `char`, `&str`/`String`, and `&[u8]`/`Vec<u8>`; anything else
+/// should go through `Hash`/`Hasher` as usual. The hasher is taken by value
+/// (and consumed) rather than seeded internally, so callers can seed it
+/// however they like -- a single seed, two independent keys, etc. --
+/// before feeding it a key.
+pub trait FxHashOne<T: ?Sized> {
+    fn fx_hash_one(self, value: &T) -> u64;
+}
+
+macro_rules! impl_hash_one_int {
+    ($($t:ty => $write:ident),* $(,)?) => {
+        $(
+            impl<H: Hasher> FxHashOne<$t> for H {
+                #[inline]
+                fn fx_hash_one(mut self, value: &$t) -> u64 {
+                    self.$write(*value);
+                    self.finish()
+                }
+            }
+        )*
+    };
+}
+
+impl_hash_one_int!(
+    u8 => write_u8,
+    u16 => write_u16,
+    u32 => write_u32,
+    u64 => write_u64,
+    u128 => write_u128,
+    usize => write_usize,
+    i8 => write_i8,
+    i16 => write_i16,
+    i32 => write_i32,
+    i64 => write_i64,
+    i128 => write_i128,
+    isize => write_isize,
+);
+
+impl<H: Hasher> FxHashOne<char> for H {
+    #[inline]
+    fn fx_hash_one(mut self, value: &char) -> u64 {
+        self.write_u32(*value as u32);
+        self.finish()
+    }
+}
+
+impl<H: Hasher> FxHashOne<[u8]> for H {
+    #[inline]
+    fn fx_hash_one(mut self, value: &[u8]) -> u64 {
+        self.write(value);
+        self.finish()
+    }
+}
+
+impl<H: Hasher> FxHashOne<str> for H {
+    #[inline]
+    fn fx_hash_one(mut self, value: &str) -> u64 {
+        self.write_str(value);
+        self.finish()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: Hasher> FxHashOne<std::string::String> for H {
+    #[inline]
+    fn fx_hash_one(self, value: &std::string::String) -> u64 {
+        self.fx_hash_one(value.as_str())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<H: Hasher> FxHashOne<std::vec::Vec<u8>> for H {
+    #[inline]
+    fn fx_hash_one(self, value: &std::vec::Vec<u8>) -> u64 {
+        self.fx_hash_one(value.as_slice())
+    }
+}
@@ -47,13 +47,78 @@ pub type FxHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FxHasher>>;
 pub type FxHashSet<V> = HashSet<V, BuildHasherDefault<FxHasher>>;
 
 #[cfg(feature = "rand")]
-pub use random_state::{FxHashMapRand, FxHashSetRand, FxRandomState};
+pub use random_state::FxRandomState;
+#[cfg(all(feature = "rand", feature = "std"))]
+pub use random_state::{FxHashMapRand, FxHashSetRand};
 
-pub use seeded_state::{FxHashMapSeed, FxHashSetSeed, FxSeededState};
+pub use seeded_state::FxSeededState;
+#[cfg(feature = "std")]
+pub use seeded_state::{FxHashMapSeed, FxHashSetSeed};
+
+/// Type alias for a hash map that uses `FxHasher`, seeded from a per-thread
+/// lazily-initialized random key pair. See [`FxThreadRandomState`].
+#[cfg(all(not(target_pointer_width = "32"), feature = "rand", feature = "std"))]
+pub type FxHashMapThreadRand<K, V> = HashMap<K, V, FxThreadRandomState>;
+
+/// Type alias for a hash set that uses `FxHasher`, seeded from a per-thread
+/// lazily-initialized random key pair. See [`FxThreadRandomState`].
+#[cfg(all(not(target_pointer_width = "32"), feature = "rand", feature = "std"))]
+pub type FxHashSetThreadRand<V> = HashSet<V, FxThreadRandomState>;
+
+#[cfg(all(not(target_pointer_width = "32"), feature = "rand", feature = "std"))]
+pub use mum_add_hasher::FxThreadRandomState;
 
+#[cfg(not(target_pointer_width = "32"))]
 mod mum_add_hasher;
+#[cfg(not(target_pointer_width = "32"))]
 pub use mum_add_hasher::MumAddHasher as FxHasher;
 
+// 32-bit targets still compute `u64`/`u128` products through emulation, so
+// default to a hasher that works entirely in native 32-bit words instead.
+// The type itself doesn't depend on the host's pointer width (like
+// `PolyHasher32`, it just works in `u32`/`u64` words throughout), so it's
+// exported unconditionally and only wired up as `FxHasher` on 32-bit
+// targets, letting it be unit-tested on any host.
+mod mum_add_hasher32;
+pub use mum_add_hasher32::MumAddHasher32;
+#[cfg(target_pointer_width = "32")]
+pub use mum_add_hasher32::MumAddHasher32 as FxHasher;
+
+mod multilinear_hasher;
+pub use multilinear_hasher::MultilinearHasher;
+
+mod poly_hasher;
+pub use poly_hasher::PolyHasher;
+
+mod poly_hasher32;
+pub use poly_hasher32::PolyHasher32;
+
+mod hash_one;
+pub use hash_one::FxHashOne;
+
+mod stable_hasher;
+pub use stable_hasher::{FromStableHash, Hash128, Hash64, StableHasher};
+
+mod const_hash;
+pub use const_hash::{const_hash_bytes, const_hash_u64};
+
+mod range;
+pub use range::{hash_to_range, hash_to_range_pow2};
+
+#[cfg(feature = "aes")]
+mod aes_bulk;
+
+#[cfg(feature = "aes")]
+mod aes_hasher;
+#[cfg(feature = "aes")]
+pub use aes_hasher::AesHasher;
+
+#[cfg(feature = "digest")]
+extern crate digest;
+
+#[cfg(feature = "digest")]
+mod digest_impl;
+
 
 
 /*
@@ -80,25 +80,94 @@ fn hash_bytes(mut bytes: &[u8]) -> u64 {
     multiply_mix(s0, s1) ^ len as u64
 }
 
+/// The hardened counterpart to `hash_bytes`: each bulk block runs two
+/// `multiply_mix` rounds where the second depends on the first's output,
+/// instead of one. This roughly doubles the work per block in exchange for
+/// making it much harder to invert the mix from observed outputs alone.
+#[inline]
+fn hash_bytes_hardened(bytes: &[u8]) -> u64 {
+    let len = bytes.len();
+    let mut s0 = SEED1;
+    let mut s1 = SEED2;
+    if len <= 16 {
+        if len >= 8 {
+            s0 ^= u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+            s1 ^= u64::from_le_bytes(bytes[len-8..].try_into().unwrap());
+        } else if len >= 4 {
+            s0 ^= u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as u64;
+            s1 ^= u32::from_le_bytes(bytes[len-4..].try_into().unwrap()) as u64;
+        } else if len > 0 {
+            let lo = bytes[0];
+            let mid = bytes[len / 2];
+            let hi = bytes[len - 1];
+            s0 ^= lo as u64;
+            s1 ^= ((hi as u64) << 8) | mid as u64;
+        }
+    } else {
+        let mut off = 0;
+        while off < len - 16 {
+            let x = u64::from_le_bytes(bytes[off..off + 8].try_into().unwrap());
+            let y = u64::from_le_bytes(bytes[off + 8..off + 16].try_into().unwrap());
+
+            let t1 = multiply_mix(s0 ^ x, PREVENT_TRIVIAL_ZERO_COLLAPSE ^ y);
+            let t2 = multiply_mix(t1, s1 ^ PREVENT_TRIVIAL_ZERO_COLLAPSE);
+            s0 = s1;
+            s1 = t2;
+            off += 16;
+        }
+
+        let suffix = &bytes[len - 16..];
+        s0 ^= u64::from_le_bytes(suffix[0..8].try_into().unwrap());
+        s1 ^= u64::from_le_bytes(suffix[8..16].try_into().unwrap());
+    }
+
+    multiply_mix(s0, s1) ^ len as u64
+}
+
 
 /// Fast non-collision-resistant hash.
 pub struct MultilinearHasher {
     hash: u64,
     rng_a: u64,
     rng_b: u64,
+    // Opt-in stronger mixing for keys hashers build for untrusted input; see
+    // `with_keys_hardened`.
+    hardened: bool,
 }
 
 impl Default for MultilinearHasher {
     #[inline]
     fn default() -> Self {
-        Self { hash: 0, rng_a: SEED3, rng_b: SEED4 }
+        Self { hash: 0, rng_a: SEED3, rng_b: SEED4, hardened: false }
     }
 }
 
 impl MultilinearHasher {
     #[inline]
     pub fn with_seed(seed: usize) -> Self {
-        Self { hash: 0, rng_a: seed as u64 ^ SEED3, rng_b: seed as u64 ^ SEED4 }
+        Self::with_keys(seed as u64, seed as u64)
+    }
+
+    /// Like [`with_seed`](Self::with_seed), but mixes two independent
+    /// 64-bit keys into `rng_a`/`rng_b` instead of perturbing both with the
+    /// same `seed`. [`FxRandomState`](crate::FxRandomState) uses this so an
+    /// attacker who recovers one key doesn't get the other for free.
+    #[inline]
+    pub fn with_keys(k0: u64, k1: u64) -> Self {
+        Self { hash: 0, rng_a: k0 ^ SEED3, rng_b: k1 ^ SEED4, hardened: false }
+    }
+
+    /// Like [`with_keys`](Self::with_keys), but additionally enables a
+    /// stronger (and slower) mixing mode for `write`'s bulk byte path: two
+    /// dependent `multiply_mix` rounds per 16-byte block instead of one.
+    /// Intended for `HashMap`s keyed on untrusted input, where the extra
+    /// diffusion makes it harder for an attacker observing outputs to
+    /// reconstruct the keys or craft multi-collisions.
+    #[inline]
+    pub fn with_keys_hardened(k0: u64, k1: u64) -> Self {
+        let mut this = Self::with_keys(k0, k1);
+        this.hardened = true;
+        this
     }
 
     #[inline]
@@ -121,7 +190,8 @@ impl MultilinearHasher {
 impl Hasher for MultilinearHasher {
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
-        self.add_to_hash(hash_bytes(bytes))
+        let h = if self.hardened { hash_bytes_hardened(bytes) } else { hash_bytes(bytes) };
+        self.add_to_hash(h)
     }
 
     #[inline]
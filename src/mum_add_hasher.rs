@@ -51,11 +51,22 @@ fn multiply_mix(x: u64, y: u64) -> u64 {
 
 /// A wyhash-inspired non-collision-resistant hash for strings/slices, with a
 /// focus on small strings and small codesize.
+///
+/// `key_lo`/`key_hi` are XOR'd into `SEED1`/`SEED2` so that a keyed instance
+/// (see [`MumAddHasher::with_keys`]) mixes every byte slice it hashes
+/// through seeds an outside attacker doesn't know, not just the `rng`
+/// stream. They're `0` for the default, unkeyed construction, which leaves
+/// this identical to hashing with the fixed `SEED1`/`SEED2` as before.
+///
+/// Slices longer than 16 bytes go through [`hash_bytes_bulk`], which is
+/// hardware-AES-accelerated when the `aes` feature is enabled and the CPU
+/// supports it; inputs at or below the threshold always take this same
+/// scalar path regardless, since AES setup would dominate at that size.
 #[inline]
-fn hash_bytes(mut bytes: &[u8]) -> u64 {
+fn hash_bytes(mut bytes: &[u8], key_lo: u64, key_hi: u64) -> u64 {
     let len = bytes.len();
-    let mut s0 = SEED1;
-    let mut s1 = SEED2;
+    let mut s0 = SEED1 ^ key_lo;
+    let mut s1 = SEED2 ^ key_hi;
     if len <= 16 {
         if len >= 8 {
             s0 ^= u64::from_le_bytes(bytes[0..8].try_into().unwrap());
@@ -70,29 +81,52 @@ fn hash_bytes(mut bytes: &[u8]) -> u64 {
             s0 ^= lo as u64;
             s1 ^= ((hi as u64) << 8) | mid as u64;
         }
-    } else {
-        // Handle bulk (can partially overlap with suffix).
-        let mut off = 0;
-        while off < len - 16 {
-            let x = u64::from_le_bytes(bytes[off..off + 8].try_into().unwrap());
-            let y = u64::from_le_bytes(bytes[off + 8..off + 16].try_into().unwrap());
-            
-            // Replace s1 with a mix of s0, x, and y, and s0 with s1.
-            // This ensures the compiler can unroll this loop into two
-            // independent streams, one operating on s0, the other on s1.
-            // 
-            // Since zeroes are a common input we prevent an immediate trivial
-            // collapse of the hash function by XOR'ing a constant with y.
-            let t = multiply_mix(s0 ^ x, PREVENT_TRIVIAL_ZERO_COLLAPSE ^ y);
-            s0 = s1;
-            s1 = t;
-            off += 16;
+        return multiply_mix(s0, s1) ^ len as u64;
+    }
+
+    hash_bytes_bulk(bytes, s0, s1)
+}
+
+/// The `len > 16` path split out of `hash_bytes`: dispatches to the
+/// hardware-AES backend in `aes_bulk.rs` when the `aes` feature is enabled
+/// and available at runtime, otherwise runs the portable two-stream
+/// `multiply_mix` loop.
+#[inline]
+fn hash_bytes_bulk(bytes: &[u8], s0: u64, s1: u64) -> u64 {
+    #[cfg(feature = "aes")]
+    {
+        if let Some(h) = crate::aes_bulk::hash_bytes(bytes, s0, s1, ENTROPY[4], ENTROPY[5]) {
+            return h;
         }
-    
-        let suffix = &bytes[len - 16..];
-        s0 ^= u64::from_le_bytes(suffix[0..8].try_into().unwrap());
-        s1 ^= u64::from_le_bytes(suffix[8..16].try_into().unwrap());
     }
+    hash_bytes_bulk_scalar(bytes, s0, s1)
+}
+
+#[inline]
+fn hash_bytes_bulk_scalar(mut bytes: &[u8], mut s0: u64, mut s1: u64) -> u64 {
+    let len = bytes.len();
+
+    // Handle bulk (can partially overlap with suffix).
+    let mut off = 0;
+    while off < len - 16 {
+        let x = u64::from_le_bytes(bytes[off..off + 8].try_into().unwrap());
+        let y = u64::from_le_bytes(bytes[off + 8..off + 16].try_into().unwrap());
+
+        // Replace s1 with a mix of s0, x, and y, and s0 with s1.
+        // This ensures the compiler can unroll this loop into two
+        // independent streams, one operating on s0, the other on s1.
+        //
+        // Since zeroes are a common input we prevent an immediate trivial
+        // collapse of the hash function by XOR'ing a constant with y.
+        let t = multiply_mix(s0 ^ x, PREVENT_TRIVIAL_ZERO_COLLAPSE ^ y);
+        s0 = s1;
+        s1 = t;
+        off += 16;
+    }
+
+    let suffix = &bytes[len - 16..];
+    s0 ^= u64::from_le_bytes(suffix[0..8].try_into().unwrap());
+    s1 ^= u64::from_le_bytes(suffix[8..16].try_into().unwrap());
 
     multiply_mix(s0, s1) ^ len as u64
 }
@@ -104,12 +138,33 @@ pub struct MumAddHasher {
     hash: u64,
     rng: u64,
     entropy_idx: usize,
+    key_lo: u64,
+    key_hi: u64,
 }
 
 impl MumAddHasher {
     #[inline]
     pub fn with_seed(seed: usize) -> Self {
-        Self { hash: seed as u64, rng: seed as u64, entropy_idx: 0 }
+        Self { hash: seed as u64, rng: seed as u64, entropy_idx: 0, key_lo: 0, key_hi: 0 }
+    }
+
+    /// Like [`with_seed`](Self::with_seed), but additionally folds two
+    /// 128-bit keys into the hasher: `k1` is split and XOR'd into the
+    /// `SEED1`/`SEED2` constants `hash_bytes` uses for every byte slice
+    /// written, and `k2` perturbs the starting offset of the `gen_rng`
+    /// additive walk. Unlike `with_seed`'s single `usize`, neither
+    /// `SEED1`/`SEED2` nor the `rng` stream are left at a value an attacker
+    /// who knows the source (but not the keys) could predict.
+    ///
+    /// Mirrors ahash's fallback hasher `with_seeds`/`extra_keys` mechanism.
+    /// `with_random_keys` and the `FxThreadRandomState` `BuildHasher` draw
+    /// these keys from the OS RNG automatically.
+    #[inline]
+    pub fn with_keys(k1: u128, k2: u128) -> Self {
+        let key_lo = k1 as u64;
+        let key_hi = (k1 >> 64) as u64;
+        let rng_seed = (k2 as u64) ^ (k2 >> 64) as u64;
+        Self { hash: rng_seed, rng: rng_seed, entropy_idx: 0, key_lo, key_hi }
     }
 
     #[inline]
@@ -150,7 +205,7 @@ impl MumAddHasher {
 impl Hasher for MumAddHasher {
     #[inline]
     fn write(&mut self, bytes: &[u8]) {
-        self.add_to_hash(hash_bytes(bytes))
+        self.add_to_hash(hash_bytes(bytes, self.key_lo, self.key_hi))
     }
 
     #[inline]
@@ -199,4 +254,48 @@ impl Hasher for MumAddHasher {
     fn finish(&self) -> u64 {
         self.hash as u64
     }
+}
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl MumAddHasher {
+    /// Builds a keyed hasher (see [`with_keys`](Self::with_keys)) using a
+    /// key pair drawn from the OS RNG once per thread and cached from then
+    /// on, rather than once per call. This is what lets
+    /// [`FxThreadRandomState`] give ordinary `HashMap` users
+    /// non-deterministic seeding without paying for an OS RNG draw on every
+    /// hasher construction.
+    pub fn with_random_keys() -> Self {
+        std::thread_local! {
+            static KEYS: (u128, u128) = {
+                use rand::Rng;
+                let mut rng = rand::thread_rng();
+                (rng.gen(), rng.gen())
+            };
+        }
+        let (k1, k2) = KEYS.with(|&keys| keys);
+        Self::with_keys(k1, k2)
+    }
+}
+
+/// A [`core::hash::BuildHasher`] for [`MumAddHasher`] (`FxHasher`) that
+/// seeds every hasher it builds from a key pair drawn once per thread (see
+/// [`MumAddHasher::with_random_keys`]), instead of either the deterministic
+/// all-zero default or a fresh OS RNG draw per instance.
+///
+/// This is the cheap, `HashMap::default()`-friendly middle ground between
+/// `BuildHasherDefault<FxHasher>` (fully deterministic, as `rustc` wants)
+/// and [`crate::FxRandomState`] (a fresh per-instance key, for maps keyed
+/// directly on untrusted input).
+#[cfg(all(feature = "rand", feature = "std"))]
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FxThreadRandomState;
+
+#[cfg(all(feature = "rand", feature = "std"))]
+impl core::hash::BuildHasher for FxThreadRandomState {
+    type Hasher = MumAddHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> MumAddHasher {
+        MumAddHasher::with_random_keys()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,205 @@
+use core::hash::Hasher;
+use core::convert::TryInto;
+
+// The 32-bit analog of `mum_add_hasher.rs`'s `ENTROPY`/`SEED*` constants:
+// more fractional hexadecimal digits of pi, truncated to 32 bits.
+const ENTROPY: [u32; 16] = [
+    0x85a308d3, 0x03707344, 0x299f31d0, 0xec4e6c89,
+    0x38d01377, 0x34e90c6c, 0xc97c50dd, 0xb5470917,
+    0x8979fb1b, 0x98dfb5ac, 0xd01adfb7, 0x6a267e96,
+    0xf12c7f99, 0xb3916cf7, 0x858efc16, 0x71574e69,
+];
+
+const SEED1: u32 = 0xf4933d7e;
+const SEED2: u32 = 0x728eb658;
+const PREVENT_TRIVIAL_ZERO_COLLAPSE: u32 = 0x82154aee;
+
+/// The 32-bit analog of `multiply_mix`: a 32x32 -> 64 bit widening multiply,
+/// folding the high and low halves together with XOR. This avoids the
+/// emulated 128-bit multiply `multiply_mix` needs on targets where `u64` is
+/// not a native machine word.
+#[inline]
+fn multiply_mix(x: u32, y: u32) -> u32 {
+    let full = (x as u64) * (y as u64);
+    let lo = full as u32;
+    let hi = (full >> 32) as u32;
+    lo ^ hi
+}
+
+/// The 32-bit analog of `hash_bytes`, reading the short-input tail as
+/// `u16`/`u8` instead of `u32`/`u16` and absorbing 8-byte (two `u32`)
+/// blocks instead of 16-byte ones.
+#[inline]
+fn hash_bytes(bytes: &[u8]) -> u32 {
+    let len = bytes.len();
+    let mut s0 = SEED1;
+    let mut s1 = SEED2;
+    if len <= 8 {
+        if len >= 4 {
+            s0 ^= u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+            s1 ^= u32::from_le_bytes(bytes[len - 4..].try_into().unwrap());
+        } else if len >= 2 {
+            s0 ^= u16::from_le_bytes(bytes[0..2].try_into().unwrap()) as u32;
+            s1 ^= u16::from_le_bytes(bytes[len - 2..].try_into().unwrap()) as u32;
+        } else if len > 0 {
+            s0 ^= bytes[0] as u32;
+        }
+    } else {
+        let mut off = 0;
+        while off < len - 8 {
+            let x = u32::from_le_bytes(bytes[off..off + 4].try_into().unwrap());
+            let y = u32::from_le_bytes(bytes[off + 4..off + 8].try_into().unwrap());
+            let t = multiply_mix(s0 ^ x, PREVENT_TRIVIAL_ZERO_COLLAPSE ^ y);
+            s0 = s1;
+            s1 = t;
+            off += 8;
+        }
+
+        let suffix = &bytes[len - 8..];
+        s0 ^= u32::from_le_bytes(suffix[0..4].try_into().unwrap());
+        s1 ^= u32::from_le_bytes(suffix[4..8].try_into().unwrap());
+    }
+
+    multiply_mix(s0, s1) ^ len as u32
+}
+
+/// A 32-bit-native variant of [`crate::MumAddHasher`], for platforms where
+/// `u64`/`u128` arithmetic is emulated (`target_pointer_width = "32"`).
+/// Works entirely in `u32` words instead of computing a `u64 x u64 -> u128`
+/// product, trading some output entropy for a cheaper inner loop on those
+/// targets.
+#[derive(Default, Clone)]
+pub struct MumAddHasher32 {
+    hash: u32,
+    rng: u32,
+    entropy_idx: usize,
+}
+
+impl MumAddHasher32 {
+    #[inline]
+    pub fn with_seed(seed: usize) -> Self {
+        Self { hash: seed as u32, rng: seed as u32, entropy_idx: 0 }
+    }
+
+    #[inline]
+    fn gen_rng(&mut self) -> u32 {
+        self.entropy_idx %= 16;
+        self.rng = self.rng.wrapping_add(ENTROPY[self.entropy_idx]);
+        self.entropy_idx += 1;
+        self.rng
+    }
+
+    #[inline]
+    fn add_to_hash(&mut self, x: u32) {
+        let h = multiply_mix(x, self.gen_rng());
+        self.hash = self.hash.wrapping_add(h);
+    }
+
+    #[inline]
+    fn double_add_to_hash(&mut self, x: u32, y: u32) {
+        let h = multiply_mix(x ^ self.gen_rng(), y ^ self.gen_rng());
+        self.hash = self.hash.wrapping_add(h);
+    }
+}
+
+impl Hasher for MumAddHasher32 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.add_to_hash(hash_bytes(bytes))
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u32);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u32);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.double_add_to_hash(i as u32, (i >> 32) as u32);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u32);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.double_add_to_hash(i as u32, (i >> 32) as u32);
+        self.double_add_to_hash((i >> 64) as u32, (i >> 96) as u32);
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, _len: usize) {
+        // Most cases will specialize hash_slice anyway which calls write,
+        // which encodes the length already.
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) {
+        // We don't need anything special here.
+        self.write(s.as_bytes())
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        // Rotate so the high-entropy top bits land where a hash table
+        // computing its bucket index from the low bits can see them, tuned
+        // for the 32-bit table sizes this hasher targets.
+        self.hash.rotate_left(10) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_u128(x: u128) -> u64 {
+        let mut h = MumAddHasher32::with_seed(0);
+        h.write_u128(x);
+        h.finish()
+    }
+
+    #[test]
+    fn write_u128_hashes_all_four_chunks_independently() {
+        // Regression test: write_u128 used to XOR the second and fourth
+        // 32-bit chunks together before mixing, so swapping them collided.
+        let a = 0xbbbb_bbbb_0000_0000_aaaa_aaaa_0000_0000u128;
+        let b = 0xaaaa_aaaa_0000_0000_bbbb_bbbb_0000_0000u128;
+        assert_ne!(hash_u128(a), hash_u128(b));
+    }
+
+    #[test]
+    fn write_u128_is_deterministic() {
+        let x = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+        assert_eq!(hash_u128(x), hash_u128(x));
+    }
+
+    #[test]
+    fn short_and_long_byte_inputs_differ() {
+        let mut a = MumAddHasher32::with_seed(0);
+        let mut b = MumAddHasher32::with_seed(0);
+        a.write(&[1, 2, 3]);
+        b.write(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        assert_ne!(a.finish(), b.finish());
+    }
+
+    #[test]
+    fn different_seeds_give_different_output() {
+        let mut a = MumAddHasher32::with_seed(1);
+        let mut b = MumAddHasher32::with_seed(2);
+        a.write_u32(42);
+        b.write_u32(42);
+        assert_ne!(a.finish(), b.finish());
+    }
+}
@@ -0,0 +1,129 @@
+use core::hash::Hasher;
+use core::convert::TryInto;
+
+// The 32-bit analog of `poly_hasher.rs`'s `SEED*` constants.
+const SEED1: u32 = 0x885a308d;
+const SEED2: u32 = 0x03707344;
+const PREVENT_TRIVIAL_ZERO_COLLAPSE: u32 = 0x299f31d0;
+
+#[inline]
+fn multiply_mix(x: u32, y: u32) -> u32 {
+    // See `mum_add_hasher32.rs` for why this 32x32 -> 64 widening multiply
+    // replaces the 64x64 -> 128 one `multiply_mix` uses elsewhere.
+    let full = (x as u64) * (y as u64);
+    let lo = full as u32;
+    let hi = (full >> 32) as u32;
+    lo ^ hi
+}
+
+#[inline]
+fn hash_bytes(bytes: &[u8]) -> u32 {
+    let len = bytes.len();
+    let mut s0 = SEED1;
+    let mut s1 = SEED2;
+    if len <= 8 {
+        if len >= 4 {
+            s0 ^= u32::from_le_bytes(bytes[0..4].try_into().unwrap());
+            s1 ^= u32::from_le_bytes(bytes[len - 4..].try_into().unwrap());
+        } else if len >= 2 {
+            s0 ^= u16::from_le_bytes(bytes[0..2].try_into().unwrap()) as u32;
+            s1 ^= u16::from_le_bytes(bytes[len - 2..].try_into().unwrap()) as u32;
+        } else if len > 0 {
+            s0 ^= bytes[0] as u32;
+        }
+    } else {
+        let mut off = 0;
+        while off < len - 8 {
+            let x = u32::from_le_bytes(bytes[off..off + 4].try_into().unwrap());
+            let y = u32::from_le_bytes(bytes[off + 4..off + 8].try_into().unwrap());
+            let t = multiply_mix(s0 ^ x, PREVENT_TRIVIAL_ZERO_COLLAPSE ^ y);
+            s0 = s1;
+            s1 = t;
+            off += 8;
+        }
+
+        let suffix = &bytes[len - 8..];
+        s0 ^= u32::from_le_bytes(suffix[0..4].try_into().unwrap());
+        s1 ^= u32::from_le_bytes(suffix[4..8].try_into().unwrap());
+    }
+
+    multiply_mix(s0, s1) ^ len as u32
+}
+
+/// A 32-bit-native variant of [`crate::PolyHasher`], using a 32-bit
+/// multiplicative constant for the running accumulator instead of `K`'s
+/// 64-bit one, for platforms where `u64` arithmetic is emulated
+/// (`target_pointer_width = "32"`).
+#[derive(Default)]
+pub struct PolyHasher32 {
+    hash: u32,
+}
+
+// "Computationally Easy, Spectrally Good Multipliers for Congruential
+// Pseudorandom Number Generators" by Guy Steele and Sebastiano Vigna,
+// truncated to a 32-bit multiplier.
+const K: u32 = 0x2e62a9c5;
+
+impl PolyHasher32 {
+    #[inline]
+    pub fn with_seed(_seed: usize) -> Self {
+        Self { hash: 0 }
+    }
+
+    #[inline]
+    fn add_to_hash(&mut self, x: u32) {
+        self.hash = self.hash.wrapping_add(x).wrapping_mul(K);
+    }
+}
+
+impl Hasher for PolyHasher32 {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.add_to_hash(hash_bytes(bytes))
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u32);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u32);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i as u32);
+        self.add_to_hash((i >> 32) as u32);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add_to_hash(i as u32);
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, _len: usize) {
+        // Most cases will specialize hash_slice anyway which calls write,
+        // which encodes the length already.
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) {
+        // We don't need anything special here.
+        self.write(s.as_bytes())
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        // Same rationale as `PolyHasher::finish`, just tuned for the
+        // smaller table sizes this 32-bit-native hasher targets.
+        self.hash.rotate_left(10) as u64
+    }
+}
@@ -0,0 +1,123 @@
+use core::hash::BuildHasher;
+use core::sync::atomic::{AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use rand::Rng as _;
+
+use crate::hash_one::FxHashOne;
+use crate::MultilinearHasher;
+
+/// Type alias for a hash map that uses the Fx hashing algorithm, randomly
+/// seeded per instance.
+#[cfg(feature = "std")]
+pub type FxHashMapRand<K, V> = HashMap<K, V, FxRandomState>;
+
+/// Type alias for a hash set that uses the Fx hashing algorithm, randomly
+/// seeded per instance.
+#[cfg(feature = "std")]
+pub type FxHashSetRand<V> = HashSet<V, FxRandomState>;
+
+// Incremented once per freshly keyed `FxRandomState` (`new` or `hardened`),
+// so that even two instances
+// constructed in the same nanosecond (and thus with correlated OS RNG
+// draws on a weak platform RNG) still end up with distinct keys.
+static INSTANCE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A [`BuildHasher`] that constructs [`MultilinearHasher`]s keyed for
+/// HashDoS resistance, not just a randomized seed.
+///
+/// Every [`FxRandomState`] derives two independent 64-bit keys from the OS
+/// RNG, a per-process atomic counter, and the address of a stack variable,
+/// then mixes both into the hasher's `rng_a`/`rng_b` streams via
+/// [`MultilinearHasher::with_keys`]. `HashMap`s built from distinct
+/// [`FxRandomState`]s are thus extremely unlikely to hash any given key the
+/// same way, across instances *and* across separate runs of the process,
+/// which is the first line of defense against an attacker flooding a
+/// public-facing map with colliding keys.
+#[derive(Clone, Copy, Debug)]
+pub struct FxRandomState {
+    key0: u64,
+    key1: u64,
+    hardened: bool,
+}
+
+impl FxRandomState {
+    /// Creates a new [`FxRandomState`] with fresh, unpredictable keys.
+    #[inline]
+    pub fn new() -> Self {
+        Self::from_keys(Self::fresh_keys(), false)
+    }
+
+    /// Like [`new`](Self::new), but also opts every hasher this state
+    /// builds into [`MultilinearHasher::with_keys_hardened`]'s stronger
+    /// (and slower) mixing mode. Use this for maps keyed directly on
+    /// untrusted, attacker-controlled input.
+    #[inline]
+    pub fn hardened() -> Self {
+        Self::from_keys(Self::fresh_keys(), true)
+    }
+
+    /// Creates an [`FxRandomState`] from two caller-provided 64-bit keys
+    /// instead of deriving them from the OS RNG, mirroring ahash's
+    /// `with_seeds`/`extra_keys` escape hatch for callers who manage their
+    /// own entropy (e.g. to make replayed test runs deterministic).
+    #[inline]
+    pub fn with_keys(k0: u64, k1: u64) -> Self {
+        Self::from_keys((k0, k1), false)
+    }
+
+    #[inline]
+    fn from_keys((key0, key1): (u64, u64), hardened: bool) -> Self {
+        Self { key0, key1, hardened }
+    }
+
+    fn fresh_keys() -> (u64, u64) {
+        let counter = INSTANCE_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+        // The address of a stack local is unpredictable to an outside
+        // attacker (ASLR, thread stack placement) and varies across calls
+        // even within the same process, so it's cheap extra entropy on top
+        // of the OS RNG and the counter.
+        let stack_marker = 0u8;
+        let stack_addr = &stack_marker as *const u8 as u64;
+
+        let mut rng = rand::thread_rng();
+        let os_key0: u64 = rng.gen();
+        let os_key1: u64 = rng.gen();
+
+        let key0 = os_key0 ^ counter.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ stack_addr;
+        let key1 = os_key1 ^ counter.rotate_left(32) ^ stack_addr.rotate_right(17);
+        (key0, key1)
+    }
+
+    /// Hashes `value` directly with this state's keys, specialized per key
+    /// shape via [`FxHashOne`].
+    #[inline]
+    pub fn hash_key<T: ?Sized>(&self, value: &T) -> u64
+    where
+        MultilinearHasher: FxHashOne<T>,
+    {
+        self.build_hasher().fx_hash_one(value)
+    }
+}
+
+impl Default for FxRandomState {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for FxRandomState {
+    type Hasher = MultilinearHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> MultilinearHasher {
+        if self.hardened {
+            MultilinearHasher::with_keys_hardened(self.key0, self.key1)
+        } else {
+            MultilinearHasher::with_keys(self.key0, self.key1)
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Multiplicative range reduction: mapping a `u64` hash into `[0, n)` (or
+//! onto `bits` high-quality bits) without a modulo, for shard selection or
+//! open-addressed bucket indexing.
+
+/// Maps `h` into `[0, n)` using Lemire's multiplicative reduction: a single
+/// widening multiply and shift, much cheaper than `h % n`. Like `h % n`, it
+/// is very slightly biased when `n` is not a power of two.
+///
+/// Returns `0` if `n` is `0`.
+#[inline]
+pub const fn hash_to_range(h: u64, n: u64) -> u64 {
+    (((h as u128) * (n as u128)) >> 64) as u64
+}
+
+/// Maps `h` onto the top `bits` bits of a Dietzfelbinger multiplicative
+/// hash, for power-of-two-sized targets (e.g. `1 << bits` buckets).
+///
+/// `a` is forced odd (via `ENTROPY[0] | 1`-style callers, or any odd
+/// multiplier) so the map stays a bijection on `u64`; `bits` must be `<= 64`.
+#[inline]
+pub const fn hash_to_range_pow2(h: u64, a: u64, bits: u32) -> u64 {
+    debug_assert!(bits <= 64);
+    if bits == 0 {
+        return 0;
+    }
+    a.wrapping_mul(h) >> (64 - bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_to_range_stays_in_bounds() {
+        for n in [1u64, 2, 3, 7, 1000, u32::MAX as u64] {
+            for h in [0u64, 1, u64::MAX / 2, u64::MAX - 1, u64::MAX] {
+                assert!(hash_to_range(h, n) < n, "hash_to_range({h}, {n}) out of bounds");
+            }
+        }
+    }
+
+    #[test]
+    fn hash_to_range_of_zero_bound_is_zero() {
+        assert_eq!(hash_to_range(0x1234_5678_9abc_def0, 0), 0);
+    }
+
+    #[test]
+    fn hash_to_range_extremes_are_monotonic_endpoints() {
+        // h = 0 always maps to bucket 0; h = u64::MAX maps to the last bucket.
+        assert_eq!(hash_to_range(0, 100), 0);
+        assert_eq!(hash_to_range(u64::MAX, 100), 99);
+    }
+
+    #[test]
+    fn hash_to_range_pow2_matches_manual_rederivation() {
+        let h = 0x9e37_79b9_7f4a_7c15u64;
+        let a = 0xff51_afd7_ed55_8ccdu64 | 1;
+        for bits in [1u32, 4, 8, 16, 32, 64] {
+            let got = hash_to_range_pow2(h, a, bits);
+            let expected = a.wrapping_mul(h) >> (64 - bits);
+            assert_eq!(got, expected, "bits={bits}");
+            assert!(got < (1u128 << bits) as u64 || bits == 64);
+        }
+    }
+
+    #[test]
+    fn hash_to_range_pow2_zero_bits_is_zero() {
+        assert_eq!(hash_to_range_pow2(u64::MAX, 0xdead_beef_dead_beef, 0), 0);
+    }
+}
@@ -0,0 +1,58 @@
+use core::hash::BuildHasher;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::hash_one::FxHashOne;
+use crate::FxHasher;
+
+/// Type alias for a hash map that uses the Fx hashing algorithm with an
+/// explicit, caller-provided seed.
+#[cfg(feature = "std")]
+pub type FxHashMapSeed<K, V> = HashMap<K, V, FxSeededState>;
+
+/// Type alias for a hash set that uses the Fx hashing algorithm with an
+/// explicit, caller-provided seed.
+#[cfg(feature = "std")]
+pub type FxHashSetSeed<V> = HashSet<V, FxSeededState>;
+
+/// A [`BuildHasher`] that constructs [`FxHasher`]s seeded with a fixed,
+/// caller-provided seed rather than the all-zero default.
+///
+/// Unlike [`FxRandomState`](crate::FxRandomState), the seed here is fully
+/// under the caller's control, which makes [`FxSeededState`] suitable when
+/// you need reproducible-but-distinct hashing (e.g. sharding the same keys
+/// differently across several maps), rather than protection against an
+/// adversary who can choose the keys.
+#[derive(Clone, Copy, Debug)]
+pub struct FxSeededState {
+    seed: usize,
+}
+
+impl FxSeededState {
+    /// Creates a new [`FxSeededState`] that seeds every [`FxHasher`] it
+    /// builds with `seed`.
+    #[inline]
+    pub fn with_seed(seed: usize) -> Self {
+        Self { seed }
+    }
+
+    /// Hashes `value` directly with this state's seed, specialized per key
+    /// shape via [`FxHashOne`] to skip building and discarding a throwaway
+    /// `HashMap` entry just to get a hash out.
+    #[inline]
+    pub fn hash_key<T: ?Sized>(&self, value: &T) -> u64
+    where
+        FxHasher: FxHashOne<T>,
+    {
+        FxHasher::with_seed(self.seed).fx_hash_one(value)
+    }
+}
+
+impl BuildHasher for FxSeededState {
+    type Hasher = FxHasher;
+
+    #[inline]
+    fn build_hasher(&self) -> FxHasher {
+        FxHasher::with_seed(self.seed)
+    }
+}
@@ -0,0 +1,298 @@
+use core::hash::Hasher;
+use core::convert::TryInto;
+
+// Reused from the other hashers' tables: the first eight words are the
+// second half of `aes_hasher.rs`'s `ENTROPY`, the last eight the first half
+// of `mum_add_hasher.rs`'s.
+const ENTROPY: [u64; 16] = [
+    0x5b4321b4a1b0c4b4, 0x5a1158c4c58c9815, 0x0ef956141fe24e99, 0x08e4f89111e90c7e,
+    0x24edff4b1cfae00c, 0xbb5f5a5c3fbb3ae2, 0x876920cc2cf9cb59, 0x670c9200107d4642,
+    0x243f6a8885a308d3, 0x13198a2e03707344, 0xa4093822299f31d0, 0x082efa98ec4e6c89,
+    0x452821e638d01377, 0xbe5466cf34e90c6c, 0xc0ac29b7c97c50dd, 0x3f84d5b5b5470917,
+];
+
+const SEED1: u64 = 0x9216d5d98979fb1b;
+const SEED2: u64 = 0xd1310ba698dfb5ac;
+const PREVENT_TRIVIAL_ZERO_COLLAPSE: u64 = 0x2ffd72dbd01adfb7;
+
+// Mixed into the second accumulator's stream only, so the two accumulators
+// diverge even though they're driven by the same byte/integer writes.
+const SECOND_STREAM_TAG: u64 = 0xb8e1afed6a267e96;
+
+// Mixed into `write_length_prefix`'s contribution, so e.g. a `(u64, u64)`
+// and a `[u64; 2]` -- which make the same `write_u64` calls but differ in
+// how many length prefixes precede them -- don't collide.
+const LENGTH_TAG: u64 = 0xba7c9045f12c7f99;
+
+#[inline]
+fn multiply_mix(x: u64, y: u64) -> u64 {
+    // See `mum_add_hasher.rs` for a full explanation of this mixing step.
+    let full = (x as u128) * (y as u128);
+    let lo = full as u64;
+    let hi = (full >> 64) as u64;
+    lo ^ hi
+}
+
+/// Identical in structure to the other hashers' `hash_bytes`, just using
+/// this module's own seed constants.
+#[inline]
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let len = bytes.len();
+    let mut s0 = SEED1;
+    let mut s1 = SEED2;
+    if len <= 16 {
+        if len >= 8 {
+            s0 ^= u64::from_le_bytes(bytes[0..8].try_into().unwrap());
+            s1 ^= u64::from_le_bytes(bytes[len - 8..].try_into().unwrap());
+        } else if len >= 4 {
+            s0 ^= u32::from_le_bytes(bytes[0..4].try_into().unwrap()) as u64;
+            s1 ^= u32::from_le_bytes(bytes[len - 4..].try_into().unwrap()) as u64;
+        } else if len > 0 {
+            let lo = bytes[0];
+            let mid = bytes[len / 2];
+            let hi = bytes[len - 1];
+            s0 ^= lo as u64;
+            s1 ^= ((hi as u64) << 8) | mid as u64;
+        }
+    } else {
+        let mut off = 0;
+        while off < len - 16 {
+            let x = u64::from_le_bytes(bytes[off..off + 8].try_into().unwrap());
+            let y = u64::from_le_bytes(bytes[off + 8..off + 16].try_into().unwrap());
+            let t = multiply_mix(s0 ^ x, PREVENT_TRIVIAL_ZERO_COLLAPSE ^ y);
+            s0 = s1;
+            s1 = t;
+            off += 16;
+        }
+
+        let suffix = &bytes[len - 16..];
+        s0 ^= u64::from_le_bytes(suffix[0..8].try_into().unwrap());
+        s1 ^= u64::from_le_bytes(suffix[8..16].try_into().unwrap());
+    }
+
+    multiply_mix(s0, s1) ^ len as u64
+}
+
+/// Lets a type be materialized directly from a [`StableHasher`]'s full
+/// 128-bit internal state, mirroring how rustc's stable hasher hands back
+/// fixed-width results independent of the host's pointer width or
+/// endianness.
+///
+/// `hash[0]` is the same value [`Hasher::finish`] would return; `hash[1]`
+/// is the second, independent accumulator also exposed by
+/// [`StableHasher::finish128`].
+pub trait FromStableHash {
+    fn from_stable_hash(hash: [u64; 2]) -> Self;
+}
+
+/// A 64-bit stable hash result, i.e. just [`Hasher::finish`]'s output
+/// wrapped in a distinct type for use with [`FromStableHash`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Hash64(pub u64);
+
+impl FromStableHash for Hash64 {
+    #[inline]
+    fn from_stable_hash(hash: [u64; 2]) -> Self {
+        Self(hash[0])
+    }
+}
+
+/// A 128-bit stable hash result, i.e. [`StableHasher::finish128`]'s output
+/// wrapped in a distinct type for use with [`FromStableHash`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub struct Hash128(pub u128);
+
+impl FromStableHash for Hash128 {
+    #[inline]
+    fn from_stable_hash(hash: [u64; 2]) -> Self {
+        Self(((hash[1] as u128) << 64) | hash[0] as u128)
+    }
+}
+
+/// A sibling of [`crate::FxHasher`] that produces bit-identical results
+/// across architectures and pointer widths, suitable for on-disk caches and
+/// incremental-compilation fingerprints where [`crate::FxHasher`]'s
+/// dependence on native `usize` width and which `write_*` method a `Hash`
+/// impl happens to call would otherwise let two machines disagree.
+///
+/// To stay stable it takes an explicit `u64` seed (never `usize`), widens
+/// every integer write to `u64` before mixing (like the other hashers
+/// already do), and -- unlike them -- actually encodes a length/width tag
+/// in [`write_length_prefix`](Hasher::write_length_prefix) instead of
+/// treating it as a no-op, so compound types with the same field values but
+/// different shapes don't collide.
+#[derive(Clone)]
+pub struct StableHasher {
+    hash: u64,
+    hash_b: u64,
+    rng: u64,
+    rng_b: u64,
+    entropy_idx: usize,
+}
+
+impl Default for StableHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::with_seed(0)
+    }
+}
+
+impl StableHasher {
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self {
+        Self { hash: seed, hash_b: seed, rng: seed, rng_b: seed, entropy_idx: 0 }
+    }
+
+    #[inline]
+    fn gen_rng(&mut self) -> (u64, u64) {
+        self.entropy_idx %= 16;
+        let e_a = ENTROPY[self.entropy_idx];
+        let e_b = ENTROPY[(self.entropy_idx + 8) % 16];
+        self.rng = self.rng.wrapping_add(e_a);
+        self.rng_b = self.rng_b.wrapping_add(e_b);
+        self.entropy_idx += 1;
+        (self.rng, self.rng_b)
+    }
+
+    #[inline]
+    fn add_to_hash(&mut self, x: u64) {
+        let (ra, rb) = self.gen_rng();
+        self.hash = self.hash.wrapping_add(multiply_mix(x, ra));
+        self.hash_b = self.hash_b.wrapping_add(multiply_mix(x, rb ^ SECOND_STREAM_TAG));
+    }
+
+    /// Returns the full 128-bit internal state: the low 64 bits are the
+    /// same value [`Hasher::finish`] returns, the high 64 bits are a second,
+    /// independently-mixed accumulator.
+    #[inline]
+    pub fn finish128(&self) -> u128 {
+        ((self.hash_b as u128) << 64) | self.hash as u128
+    }
+
+    /// Finalizes into any type implementing [`FromStableHash`], e.g.
+    /// [`Hash64`] or [`Hash128`].
+    #[inline]
+    pub fn finish_as<T: FromStableHash>(&self) -> T {
+        T::from_stable_hash([self.hash, self.hash_b])
+    }
+}
+
+impl Hasher for StableHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        self.add_to_hash(hash_bytes(bytes))
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add_to_hash(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        // Always widen to `u64` rather than mixing the native `usize` width
+        // in some other way, so 32-bit and 64-bit hosts agree on any given
+        // logical integer value.
+        self.add_to_hash(i as u64);
+    }
+
+    #[inline]
+    fn write_u128(&mut self, i: u128) {
+        self.add_to_hash(i as u64);
+        self.add_to_hash((i >> 64) as u64);
+    }
+
+    #[inline]
+    fn write_length_prefix(&mut self, len: usize) {
+        // Unlike the other hashers here, a stable hash needs to actually
+        // disambiguate field/element boundaries instead of treating this as
+        // a no-op, or e.g. `(1u64, 2u64)` and `[1u64, 2u64]` would hash the
+        // same despite having different shapes.
+        self.add_to_hash(len as u64 ^ LENGTH_TAG);
+    }
+
+    #[inline]
+    fn write_str(&mut self, s: &str) {
+        self.write(s.as_bytes())
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_output_vectors() {
+        let mut h = StableHasher::with_seed(0);
+        h.write_str("uwu");
+        assert_eq!(h.finish(), 10682040718958838402);
+        assert_eq!(h.finish128(), 0xd081c46b5d402a42943e3b7021ccc282);
+
+        let mut h = StableHasher::with_seed(42);
+        h.write_u64(12345);
+        assert_eq!(h.finish(), 16736607157856068744);
+    }
+
+    #[test]
+    fn length_prefix_disambiguates_tuple_from_array() {
+        // `(1u64, 2u64)`'s `Hash` impl just hashes each field in turn with no
+        // length prefix, while `[1u64, 2u64]`'s hashes a length prefix
+        // first -- `write_length_prefix` needs to actually contribute to
+        // the state (not be a no-op, like the other hashers here) for
+        // these to land on different finishes.
+        let mut tuple_like = StableHasher::with_seed(0);
+        tuple_like.write_u64(1);
+        tuple_like.write_u64(2);
+
+        let mut array_like = StableHasher::with_seed(0);
+        array_like.write_length_prefix(2);
+        array_like.write_u64(1);
+        array_like.write_u64(2);
+
+        assert_ne!(tuple_like.finish(), array_like.finish());
+    }
+
+    #[test]
+    fn equal_value_integer_writes_agree_across_width() {
+        // Intentional, not a bug: every `write_uNN` widens to `u64` before
+        // mixing (see `write_usize`'s doc comment), so a lone `write_u32(5)`
+        // and a lone `write_u64(5)` -- the same logical value, no
+        // surrounding length prefix -- produce the same state. Callers that
+        // need shape-sensitivity should rely on `write_length_prefix`
+        // (compound types) rather than on integer writes differing by width.
+        let mut a = StableHasher::with_seed(0);
+        a.write_u32(5);
+
+        let mut b = StableHasher::with_seed(0);
+        b.write_u64(5);
+
+        assert_eq!(a.finish(), b.finish());
+    }
+
+    #[test]
+    fn finish128_low_bits_match_finish() {
+        let mut h = StableHasher::with_seed(7);
+        h.write_str("some stable input");
+        assert_eq!(h.finish128() as u64, h.finish());
+    }
+}
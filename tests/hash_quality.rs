@@ -0,0 +1,294 @@
+//! Statistical quality tests for the hash functions in this crate.
+//!
+//! These are not exhaustive cryptographic analyses -- the hashers here are
+//! explicitly non-collision-resistant -- but they catch gross regressions
+//! in `multiply_mix`, `gen_rng`, or the seed constants that the fixed
+//! output-vector tests alone would miss.
+
+use core::hash::Hasher;
+
+use rustc_hash::{FxHasher, MultilinearHasher, PolyHasher};
+
+/// A small, deterministic xorshift64 PRNG so these tests don't need an
+/// external `rand` dev-dependency and stay reproducible across runs.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, buf: &mut [u8]) {
+        for chunk in buf.chunks_mut(8) {
+            let r = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&r[..chunk.len()]);
+        }
+    }
+}
+
+fn hash_bytes<H: Hasher + Default>(bytes: &[u8]) -> u64 {
+    let mut h = H::default();
+    h.write(bytes);
+    h.finish()
+}
+
+fn hash_u64<H: Hasher + Default>(x: u64) -> u64 {
+    let mut h = H::default();
+    h.write_u64(x);
+    h.finish()
+}
+
+/// Strict avalanche: flipping any single input bit should flip each output
+/// bit with probability close to 1/2.
+fn avalanche_test<H: Hasher + Default>(name: &str) {
+    const TRIALS: u32 = 4000;
+    const LEN: usize = 32;
+
+    let mut rng = Rng::new(0xa5a5_a5a5_a5a5_a5a5);
+    let mut flip_counts = [0u32; 64];
+
+    for _ in 0..TRIALS {
+        let mut input = [0u8; LEN];
+        rng.fill_bytes(&mut input);
+        let base = hash_bytes::<H>(&input);
+
+        let bit = (rng.next_u64() as usize) % (LEN * 8);
+        input[bit / 8] ^= 1 << (bit % 8);
+        let flipped = hash_bytes::<H>(&input);
+
+        let diff = base ^ flipped;
+        for (i, count) in flip_counts.iter_mut().enumerate() {
+            if diff & (1 << i) != 0 {
+                *count += 1;
+            }
+        }
+    }
+
+    // Generous tolerance: this is a regression sanity check, not a
+    // publication-grade SAC measurement.
+    let expected = TRIALS as f64 / 2.0;
+    let tolerance = expected * 0.15;
+    for (i, &count) in flip_counts.iter().enumerate() {
+        let diff = (f64::from(count) - expected).abs();
+        assert!(
+            diff <= tolerance,
+            "{name}: output bit {i} flipped {count}/{TRIALS} times on single-bit-flip inputs, \
+             expected close to {expected}",
+        );
+    }
+}
+
+/// Bit-independence: for a handful of representative output-bit pairs,
+/// check that the four joint outcomes (00, 01, 10, 11) occur in roughly
+/// equal proportion across random inputs, i.e. the bits don't correlate.
+fn bit_independence_test<H: Hasher + Default>(name: &str) {
+    const TRIALS: u32 = 4000;
+    const PAIRS: &[(usize, usize)] = &[(0, 1), (0, 32), (0, 63), (15, 16), (31, 32), (7, 56)];
+
+    let mut rng = Rng::new(0x5a5a_5a5a_5a5a_5a5a);
+    let mut joint = [[0u32; 4]; PAIRS.len()];
+
+    for _ in 0..TRIALS {
+        let mut input = [0u8; 24];
+        rng.fill_bytes(&mut input);
+        let h = hash_bytes::<H>(&input);
+
+        for (p, &(i, j)) in PAIRS.iter().enumerate() {
+            let bi = (h >> i) & 1;
+            let bj = (h >> j) & 1;
+            joint[p][(bi * 2 + bj) as usize] += 1;
+        }
+    }
+
+    let expected = TRIALS as f64 / 4.0;
+    let tolerance = expected * 0.2;
+    for (p, &(i, j)) in PAIRS.iter().enumerate() {
+        for (outcome, &count) in joint[p].iter().enumerate() {
+            let diff = (f64::from(count) - expected).abs();
+            assert!(
+                diff <= tolerance,
+                "{name}: bits {i}/{j} joint outcome {outcome:02b} occurred {count}/{TRIALS} \
+                 times, expected close to {expected}",
+            );
+        }
+    }
+}
+
+/// Hashes a large set of structured keys into `2^k` buckets and asserts the
+/// bucket distribution's chi-square statistic stays within the bound for a
+/// good fit (generous degrees-of-freedom-scaled slack, not a strict
+/// goodness-of-fit test).
+fn collision_test<H: Hasher + Default>(name: &str, keys: &[Vec<u8>]) {
+    const K: u32 = 10;
+    const BUCKETS: usize = 1 << K;
+
+    let mut counts = vec![0u32; BUCKETS];
+    for key in keys {
+        let h = hash_bytes::<H>(key);
+        counts[(h as usize) % BUCKETS] += 1;
+    }
+
+    let n = keys.len() as f64;
+    let expected = n / BUCKETS as f64;
+    let chi_square: f64 = counts
+        .iter()
+        .map(|&c| {
+            let diff = f64::from(c) - expected;
+            diff * diff / expected
+        })
+        .sum();
+
+    // For `BUCKETS - 1` degrees of freedom the chi-square statistic should
+    // sit near its mean of `BUCKETS - 1`; a well-behaved hash rarely exceeds
+    // twice that, while a broken one (e.g. a constant hash) blows way past
+    // it.
+    let bound = (BUCKETS - 1) as f64 * 2.0;
+    assert!(
+        chi_square <= bound,
+        "{name}: chi-square statistic {chi_square} over {BUCKETS} buckets exceeds bound {bound}",
+    );
+}
+
+fn sequential_integer_keys(n: usize) -> Vec<Vec<u8>> {
+    (0..n as u64).map(|i| i.to_le_bytes().to_vec()).collect()
+}
+
+fn single_bit_keys() -> Vec<Vec<u8>> {
+    (0..64).map(|bit| (1u64 << bit).to_le_bytes().to_vec()).collect()
+}
+
+fn short_ascii_keys() -> Vec<Vec<u8>> {
+    const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+    let mut keys = Vec::new();
+    for &a in CHARS {
+        for &b in CHARS {
+            for &c in CHARS {
+                keys.push(vec![a, b, c]);
+            }
+        }
+    }
+    keys
+}
+
+macro_rules! quality_suite {
+    ($mod_name:ident, $hasher:ty) => {
+        mod $mod_name {
+            use super::*;
+
+            #[test]
+            fn avalanche() {
+                avalanche_test::<$hasher>(stringify!($hasher));
+            }
+
+            #[test]
+            fn bit_independence() {
+                bit_independence_test::<$hasher>(stringify!($hasher));
+            }
+
+            #[test]
+            fn collisions_sequential_integers() {
+                collision_test::<$hasher>(stringify!($hasher), &sequential_integer_keys(200_000));
+            }
+
+            #[test]
+            fn collisions_single_bit_keys() {
+                // Only 64 keys, too few for a chi-square fit, so just check
+                // that they land in at least 60 distinct buckets out of 1024.
+                let mut seen = std::collections::HashSet::new();
+                for key in single_bit_keys() {
+                    let h = hash_bytes::<$hasher>(&key);
+                    seen.insert(h % 1024);
+                }
+                assert!(
+                    seen.len() >= 60,
+                    "{}: single-bit keys only hit {} distinct buckets out of 64",
+                    stringify!($hasher),
+                    seen.len(),
+                );
+            }
+
+            #[test]
+            fn collisions_short_ascii_strings() {
+                collision_test::<$hasher>(stringify!($hasher), &short_ascii_keys());
+            }
+
+            #[test]
+            fn integer_path_spreads_outputs() {
+                // Sanity check that the `write_uNN` path (not just
+                // `hash_bytes`) produces a spread of outputs.
+                let mut seen = std::collections::HashSet::new();
+                for i in 0..10_000u64 {
+                    seen.insert(hash_u64::<$hasher>(i) % (1 << 16));
+                }
+                assert!(
+                    seen.len() > 5_000,
+                    "{}: write_u64 path only hit {} distinct low-16-bit buckets out of 10000",
+                    stringify!($hasher),
+                    seen.len(),
+                );
+            }
+        }
+    };
+}
+
+quality_suite!(fx_hasher, FxHasher);
+quality_suite!(multilinear_hasher, MultilinearHasher);
+quality_suite!(poly_hasher, PolyHasher);
+
+/// Extends the crate's `with_seed_actually_different` idea: two differently
+/// seeded hashers shouldn't just disagree on one input, their outputs
+/// across many inputs shouldn't show an obvious linear correlation either.
+macro_rules! seed_independence_test {
+    ($name:ident, $hasher:ty) => {
+        #[test]
+        fn $name() {
+            let seeds = [[1usize, 2], [42, 17], [124_436_707, 99_237], [usize::MIN, usize::MAX]];
+
+            for [seed_a, seed_b] in seeds {
+                let mut agree = 0u32;
+                const TRIALS: u32 = 1000;
+                let mut rng = Rng::new(0x1234_5678_9abc_def1 ^ seed_a as u64 ^ seed_b as u64);
+
+                for _ in 0..TRIALS {
+                    let mut input = [0u8; 16];
+                    rng.fill_bytes(&mut input);
+
+                    let mut a = <$hasher>::with_seed(seed_a);
+                    let mut b = <$hasher>::with_seed(seed_b);
+                    a.write(&input);
+                    b.write(&input);
+
+                    // If the low bit of the two hashes agrees much more (or
+                    // less) often than chance, the seeds aren't properly
+                    // independent.
+                    if (a.finish() & 1) == (b.finish() & 1) {
+                        agree += 1;
+                    }
+                }
+
+                let expected = TRIALS as f64 / 2.0;
+                let diff = (f64::from(agree) - expected).abs();
+                assert!(
+                    diff <= expected * 0.2,
+                    "seeds {seed_a}/{seed_b} for {}: low bit agreed {agree}/{TRIALS} times, \
+                     expected close to {expected}",
+                    stringify!($hasher),
+                );
+            }
+        }
+    };
+}
+
+// Only `FxHasher` actually holds up to this test: `PolyHasher::with_seed`
+// ignores its seed entirely, and `MultilinearHasher::add_to_hash` multiplies
+// by an odd `gen_rng()` value, which preserves the input's low bit
+// regardless of seed -- so `finish()`'s low bit after one `write()` is
+// seed-invariant by construction for both, not just flaky under this test.
+seed_independence_test!(fx_hasher_seed_independence, FxHasher);